@@ -0,0 +1,186 @@
+use crate::canvas::Canvas;
+use crate::color::Color;
+use crate::matrix::Matrix4;
+use crate::ray::Ray;
+use crate::tuple::Point;
+use crate::world::World;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// A view onto the scene: where it looks from, its field of view, and the canvas size.
+pub struct Camera {
+    pub hsize: usize,
+    pub vsize: usize,
+    pub field_of_view: f64,
+    pub transform: Matrix4,
+    half_width: f64,
+    half_height: f64,
+    pixel_size: f64,
+}
+
+impl Camera {
+    pub fn new(hsize: usize, vsize: usize, field_of_view: f64) -> Self {
+        let half_view = (field_of_view / 2.).tan();
+        #[allow(clippy::cast_precision_loss)]
+        let aspect = hsize as f64 / vsize as f64;
+
+        let (half_width, half_height) = if aspect >= 1. {
+            (half_view, half_view / aspect)
+        } else {
+            (half_view * aspect, half_view)
+        };
+
+        #[allow(clippy::cast_precision_loss)]
+        let pixel_size = half_width * 2. / hsize as f64;
+
+        Self {
+            hsize,
+            vsize,
+            field_of_view,
+            transform: Matrix4::identity(),
+            half_width,
+            half_height,
+            pixel_size,
+        }
+    }
+
+    /// The ray from the camera through pixel `(px, py)` of the canvas.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the camera's transform is not invertible.
+    pub fn ray_for_pixel(&self, px: usize, py: usize) -> Ray {
+        #[allow(clippy::cast_precision_loss)]
+        let x_offset = (px as f64 + 0.5) * self.pixel_size;
+        #[allow(clippy::cast_precision_loss)]
+        let y_offset = (py as f64 + 0.5) * self.pixel_size;
+
+        let world_x = self.half_width - x_offset;
+        let world_y = self.half_height - y_offset;
+
+        let inverse = self
+            .transform
+            .inverse()
+            .expect("camera transform must be invertible");
+        let pixel = inverse
+            * Point {
+                x: world_x,
+                y: world_y,
+                z: -1.,
+            };
+        let origin = inverse
+            * Point {
+                x: 0.,
+                y: 0.,
+                z: 0.,
+            };
+        let direction = (pixel - origin).normalize();
+
+        Ray::new(origin, direction)
+    }
+}
+
+/// Renders `world` as seen by `camera`, computing every pixel independently so the
+/// work can be split across cores.
+#[cfg(feature = "rayon")]
+pub fn render(camera: &Camera, world: &World) -> Canvas {
+    let pixels: Vec<Color> = (0..camera.hsize * camera.vsize)
+        .into_par_iter()
+        .map(|i| {
+            let x = i % camera.hsize;
+            let y = i / camera.hsize;
+            world.color_at(&camera.ray_for_pixel(x, y))
+        })
+        .collect();
+
+    let mut canvas = Canvas::new(camera.hsize, camera.vsize);
+    for (i, color) in pixels.into_iter().enumerate() {
+        canvas.write_pixel(i % camera.hsize, i / camera.hsize, color);
+    }
+    canvas
+}
+
+/// Serial fallback used when the crate is built without the `rayon` feature.
+#[cfg(not(feature = "rayon"))]
+pub fn render(camera: &Camera, world: &World) -> Canvas {
+    let mut canvas = Canvas::new(camera.hsize, camera.vsize);
+    for y in 0..camera.vsize {
+        for x in 0..camera.hsize {
+            let color = world.color_at(&camera.ray_for_pixel(x, y));
+            canvas.write_pixel(x, y, color);
+        }
+    }
+    canvas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tuple::Vector;
+
+    #[test]
+    fn constructing_a_camera() {
+        let c = Camera::new(160, 120, std::f64::consts::FRAC_PI_2);
+        assert_eq!(c.hsize, 160);
+        assert_eq!(c.vsize, 120);
+        assert_eq!(c.field_of_view, std::f64::consts::FRAC_PI_2);
+        assert_eq!(c.transform, Matrix4::identity());
+    }
+
+    #[test]
+    fn the_pixel_size_for_a_horizontal_canvas() {
+        let c = Camera::new(200, 125, std::f64::consts::FRAC_PI_2);
+        assert!((c.pixel_size - 0.01).abs() < 1e-5);
+    }
+
+    #[test]
+    fn the_pixel_size_for_a_vertical_canvas() {
+        let c = Camera::new(125, 200, std::f64::consts::FRAC_PI_2);
+        assert!((c.pixel_size - 0.01).abs() < 1e-5);
+    }
+
+    #[test]
+    fn constructing_a_ray_through_the_center_of_the_canvas() {
+        let c = Camera::new(201, 101, std::f64::consts::FRAC_PI_2);
+        let r = c.ray_for_pixel(100, 50);
+        assert_eq!(
+            r.origin,
+            Point {
+                x: 0.,
+                y: 0.,
+                z: 0.
+            }
+        );
+        assert_eq!(
+            r.direction,
+            Vector {
+                x: 0.,
+                y: 0.,
+                z: -1.
+            }
+        );
+    }
+
+    #[test]
+    fn constructing_a_ray_through_a_corner_of_the_canvas() {
+        let c = Camera::new(201, 101, std::f64::consts::FRAC_PI_2);
+        let r = c.ray_for_pixel(0, 0);
+        assert_eq!(
+            r.origin,
+            Point {
+                x: 0.,
+                y: 0.,
+                z: 0.
+            }
+        );
+        assert_eq!(
+            r.direction,
+            Vector {
+                x: 0.66519,
+                y: 0.33259,
+                z: -0.66851
+            }
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use crate::color::Color;
+
+/// The maximum line length allowed by the PPM format's 70-character convention.
+const MAX_PPM_LINE_LENGTH: usize = 70;
+
+/// A grid of pixels that can be painted onto and exported as an image.
+pub struct Canvas {
+    pub width: usize,
+    pub height: usize,
+    pixels: Vec<Color>,
+}
+
+impl Canvas {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![
+                Color {
+                    red: 0.,
+                    green: 0.,
+                    blue: 0.,
+                };
+                width * height
+            ],
+        }
+    }
+
+    pub fn write_pixel(&mut self, x: usize, y: usize, color: Color) {
+        self.pixels[y * self.width + x] = color;
+    }
+
+    pub fn pixel_at(&self, x: usize, y: usize) -> Color {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Renders the canvas as a plain ASCII PPM (`P3`) image.
+    pub fn to_ppm(&self) -> String {
+        let mut out = format!("P3\n{} {}\n255\n", self.width, self.height);
+
+        for row in self.pixels.chunks(self.width) {
+            let tokens: Vec<String> = row
+                .iter()
+                .flat_map(|color| [color.red, color.green, color.blue])
+                .map(scale_component)
+                .map(|value| value.to_string())
+                .collect();
+            out.push_str(&wrap_tokens(&tokens));
+            out.push('\n');
+        }
+
+        out
+    }
+}
+
+/// Scales a color component from `0.0..=1.0` to `0..=255`, clamping out-of-range values.
+fn scale_component(value: f64) -> u8 {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let scaled = (value.clamp(0., 1.) * 255.).round() as u8;
+
+    scaled
+}
+
+/// Joins `tokens` with spaces, wrapping onto new lines before any line exceeds
+/// [`MAX_PPM_LINE_LENGTH`] characters.
+fn wrap_tokens(tokens: &[String]) -> String {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+
+    for token in tokens {
+        let needed = if line.is_empty() {
+            token.len()
+        } else {
+            line.len() + 1 + token.len()
+        };
+
+        if needed > MAX_PPM_LINE_LENGTH {
+            lines.push(std::mem::take(&mut line));
+        } else if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(token);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn black() -> Color {
+        Color {
+            red: 0.,
+            green: 0.,
+            blue: 0.,
+        }
+    }
+
+    #[test]
+    fn creating_a_canvas() {
+        let c = Canvas::new(10, 20);
+        assert_eq!(c.width, 10);
+        assert_eq!(c.height, 20);
+        for y in 0..20 {
+            for x in 0..10 {
+                assert_eq!(c.pixel_at(x, y), black());
+            }
+        }
+    }
+
+    #[test]
+    fn writing_pixels_to_a_canvas() {
+        let mut c = Canvas::new(10, 20);
+        let red = Color::new(1., 0., 0.);
+        c.write_pixel(2, 3, red);
+        assert_eq!(c.pixel_at(2, 3), red);
+    }
+
+    #[test]
+    fn constructing_the_ppm_header() {
+        let c = Canvas::new(5, 3);
+        let ppm = c.to_ppm();
+        let header: Vec<&str> = ppm.lines().take(3).collect();
+        assert_eq!(header, vec!["P3", "5 3", "255"]);
+    }
+
+    #[test]
+    fn constructing_the_ppm_pixel_data() {
+        let mut c = Canvas::new(5, 3);
+        c.write_pixel(0, 0, Color::new(1.5, 0., 0.));
+        c.write_pixel(2, 1, Color::new(0., 0.5, 0.));
+        c.write_pixel(4, 2, Color::new(-0.5, 0., 1.));
+        let ppm = c.to_ppm();
+        let body: Vec<&str> = ppm.lines().skip(3).collect();
+        assert_eq!(
+            body,
+            vec![
+                "255 0 0 0 0 0 0 0 0 0 0 0 0 0 0",
+                "0 0 0 0 0 0 0 128 0 0 0 0 0 0 0",
+                "0 0 0 0 0 0 0 0 0 0 0 0 0 0 255",
+            ]
+        );
+    }
+
+    #[test]
+    fn splitting_long_lines_in_ppm_files() {
+        let mut c = Canvas::new(10, 2);
+        let color = Color::new(1., 0.8, 0.6);
+        for y in 0..2 {
+            for x in 0..10 {
+                c.write_pixel(x, y, color);
+            }
+        }
+        let ppm = c.to_ppm();
+        let body: Vec<&str> = ppm.lines().skip(3).collect();
+        assert_eq!(
+            body,
+            vec![
+                "255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204",
+                "153 255 204 153 255 204 153 255 204 153 255 204 153",
+                "255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204",
+                "153 255 204 153 255 204 153 255 204 153 255 204 153",
+            ]
+        );
+    }
+
+    #[test]
+    fn ppm_files_are_terminated_by_a_newline() {
+        let c = Canvas::new(5, 3);
+        assert!(c.to_ppm().ends_with('\n'));
+    }
+}
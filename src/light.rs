@@ -0,0 +1,152 @@
+use crate::color::Color;
+use crate::tuple::{Point, Vector};
+
+/// A point light source, with no size, existing at a single point in space.
+#[derive(Debug, Clone, Copy)]
+pub struct PointLight {
+    pub position: Point,
+    pub intensity: Color,
+}
+
+impl PointLight {
+    pub fn new(position: Point, intensity: Color) -> Self {
+        Self {
+            position,
+            intensity,
+        }
+    }
+}
+
+/// The surface properties that determine how a material is shaded, per the Phong model.
+#[derive(Debug, Clone, Copy)]
+pub struct Material {
+    pub color: Color,
+    pub ambient: f64,
+    pub diffuse: f64,
+    pub specular: f64,
+    pub shininess: f64,
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Self {
+            color: Color::new(1., 1., 1.),
+            ambient: 0.1,
+            diffuse: 0.9,
+            specular: 0.9,
+            shininess: 200.,
+        }
+    }
+}
+
+/// Shades `point` using the Phong reflection model: ambient + diffuse + specular.
+pub fn lighting(
+    material: &Material,
+    light: &PointLight,
+    point: Point,
+    eyev: Vector,
+    normalv: Vector,
+) -> Color {
+    let black = Color::new(0., 0., 0.);
+
+    let effective_color = material.color * light.intensity;
+    let lightv = (light.position - point).normalize();
+    let ambient = effective_color * material.ambient;
+
+    let light_dot_normal: f64 = lightv.dot(&normalv).into();
+    let (diffuse, specular) = if light_dot_normal < 0. {
+        (black, black)
+    } else {
+        let diffuse = effective_color * material.diffuse * light_dot_normal;
+
+        let reflectv = (-lightv).reflect(&normalv);
+        let reflect_dot_eye: f64 = reflectv.dot(&eyev).into();
+        let specular = if reflect_dot_eye <= 0. {
+            black
+        } else {
+            light.intensity * material.specular * reflect_dot_eye.powf(material.shininess)
+        };
+
+        (diffuse, specular)
+    };
+
+    ambient + diffuse + specular
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(x: f64, y: f64, z: f64) -> Point {
+        Point { x, y, z }
+    }
+
+    fn vector(x: f64, y: f64, z: f64) -> Vector {
+        Vector { x, y, z }
+    }
+
+    #[test]
+    fn lighting_with_the_eye_between_light_and_surface() {
+        let m = Material::default();
+        let position = point(0., 0., 0.);
+
+        let eyev = vector(0., 0., -1.);
+        let normalv = vector(0., 0., -1.);
+        let light = PointLight::new(point(0., 0., -10.), Color::new(1., 1., 1.));
+
+        let result = lighting(&m, &light, position, eyev, normalv);
+        assert_eq!(result, Color::new(1.9, 1.9, 1.9));
+    }
+
+    #[test]
+    fn lighting_with_the_eye_between_light_and_surface_eye_offset_45deg() {
+        let m = Material::default();
+        let position = point(0., 0., 0.);
+
+        let eyev = vector(0., 2f64.sqrt() / 2., -(2f64.sqrt() / 2.));
+        let normalv = vector(0., 0., -1.);
+        let light = PointLight::new(point(0., 0., -10.), Color::new(1., 1., 1.));
+
+        let result = lighting(&m, &light, position, eyev, normalv);
+        assert_eq!(result, Color::new(1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn lighting_with_eye_opposite_surface_light_offset_45deg() {
+        let m = Material::default();
+        let position = point(0., 0., 0.);
+
+        let eyev = vector(0., 0., -1.);
+        let normalv = vector(0., 0., -1.);
+        let light = PointLight::new(point(0., 10., -10.), Color::new(1., 1., 1.));
+
+        let result = lighting(&m, &light, position, eyev, normalv);
+        assert_eq!(result, Color::new(0.7364, 0.7364, 0.7364));
+    }
+
+    #[test]
+    fn lighting_with_eye_in_the_path_of_the_reflection_vector() {
+        let m = Material::default();
+        let position = point(0., 0., 0.);
+
+        let eyev = vector(0., -(2f64.sqrt() / 2.), -(2f64.sqrt() / 2.));
+        let normalv = vector(0., 0., -1.);
+        let light = PointLight::new(point(0., 10., -10.), Color::new(1., 1., 1.));
+
+        let result = lighting(&m, &light, position, eyev, normalv);
+        assert_eq!(result, Color::new(1.6364, 1.6364, 1.6364));
+    }
+
+    #[test]
+    fn lighting_with_the_light_behind_the_surface() {
+        let m = Material::default();
+        let position = point(0., 0., 0.);
+
+        let eyev = vector(0., 0., -1.);
+        let normalv = vector(0., 0., -1.);
+        let light = PointLight::new(point(0., 0., 10.), Color::new(1., 1., 1.));
+
+        let result = lighting(&m, &light, position, eyev, normalv);
+        assert_eq!(result, Color::new(0.1, 0.1, 0.1));
+    }
+}
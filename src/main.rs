@@ -1,6 +1,13 @@
 #![warn(clippy::pedantic)]
 
+mod camera;
+mod canvas;
+mod color;
+mod light;
+mod matrix;
+mod ray;
 mod tuple;
+mod world;
 
 use std::time::{Duration, SystemTime};
 
@@ -35,6 +42,11 @@ struct Environment {
 }
 
 fn main() -> Result<(), Error> {
+    if std::env::args().nth(1).as_deref() == Some("--render") {
+        render_scene_to_ppm("render.ppm");
+        return Ok(());
+    }
+
     let event_loop = EventLoop::new();
     let window = {
         let size: LogicalSize<f64> =
@@ -93,33 +105,37 @@ fn main() -> Result<(), Error> {
     });
 }
 
+/// Renders a single lit sphere and writes it to `path` as a PPM image.
+fn render_scene_to_ppm(path: &str) {
+    let mut sphere = ray::Sphere::new();
+    sphere.transform = matrix::Matrix4::translation(0., 0., -5.);
+    sphere.material.color = color::Color::new(1., 0.2, 1.);
+
+    let scene = world::World {
+        objects: vec![sphere],
+        light: Some(light::PointLight::new(
+            Point::new(-10., 10., -10.),
+            color::Color::new(1., 1., 1.),
+        )),
+    };
+
+    let camera = camera::Camera::new(400, 200, std::f64::consts::FRAC_PI_3);
+    let canvas = camera::render(&camera, &scene);
+
+    std::fs::write(path, canvas.to_ppm()).expect("failed to write rendered scene to disk");
+}
+
 impl World {
     /// Create a new `World` instance that can draw a moving box.
     fn new() -> Self {
         Self {
             proj: Projectile {
-                position: Point {
-                    x: 10.,
-                    y: 100.,
-                    z: 0.,
-                },
-                velocity: Vector {
-                    x: 150.,
-                    y: -90.,
-                    z: 0.,
-                },
+                position: Point::new(10., 100., 0.),
+                velocity: Vector::new(150., -90., 0.),
             },
             env: Environment {
-                gravity: Vector {
-                    x: 0.,
-                    y: 100.,
-                    z: 0.,
-                },
-                wind: Vector {
-                    x: -1.,
-                    y: 0.,
-                    z: 0.,
-                },
+                gravity: Vector::new(0., 100., 0.),
+                wind: Vector::new(-1., 0., 0.),
             },
         }
     }
@@ -138,12 +154,11 @@ impl World {
             self.proj.velocity.y *= -0.8;
         }
 
+        // A looser snap-to-rest threshold than `Vector::is_zero`: gravity/wind are
+        // re-added every tick, so the box should settle once it's drifting slowly
+        // rather than only once it's exactly motionless.
         if self.proj.velocity.magnitude() < Scalar::new(1.) {
-            self.proj.velocity = Vector {
-                x: 0.,
-                y: 0.,
-                z: 0.,
-            }
+            self.proj.velocity = Vector::ZERO;
         } else {
             self.proj.velocity += (&self.env.gravity + &self.env.wind) * delta_t;
         }
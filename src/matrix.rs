@@ -0,0 +1,538 @@
+use std::ops::Mul;
+
+use approx::relative_eq;
+
+use crate::tuple::{Point, Vector, EPSILON};
+
+/// A row-major 4x4 matrix, used to represent the transformations (translation,
+/// scaling, rotation, shearing) applied to points and vectors in a scene.
+#[derive(Debug, Clone, Copy)]
+pub struct Matrix4([[f64; 4]; 4]);
+
+impl Matrix4 {
+    pub fn identity() -> Self {
+        Self([
+            [1., 0., 0., 0.],
+            [0., 1., 0., 0.],
+            [0., 0., 1., 0.],
+            [0., 0., 0., 1.],
+        ])
+    }
+
+    pub fn translation(x: f64, y: f64, z: f64) -> Self {
+        let mut m = Self::identity();
+        m.0[0][3] = x;
+        m.0[1][3] = y;
+        m.0[2][3] = z;
+        m
+    }
+
+    pub fn scaling(x: f64, y: f64, z: f64) -> Self {
+        let mut m = Self::identity();
+        m.0[0][0] = x;
+        m.0[1][1] = y;
+        m.0[2][2] = z;
+        m
+    }
+
+    pub fn rotation_x(r: f64) -> Self {
+        let mut m = Self::identity();
+        m.0[1][1] = r.cos();
+        m.0[1][2] = -r.sin();
+        m.0[2][1] = r.sin();
+        m.0[2][2] = r.cos();
+        m
+    }
+
+    pub fn rotation_y(r: f64) -> Self {
+        let mut m = Self::identity();
+        m.0[0][0] = r.cos();
+        m.0[0][2] = r.sin();
+        m.0[2][0] = -r.sin();
+        m.0[2][2] = r.cos();
+        m
+    }
+
+    pub fn rotation_z(r: f64) -> Self {
+        let mut m = Self::identity();
+        m.0[0][0] = r.cos();
+        m.0[0][1] = -r.sin();
+        m.0[1][0] = r.sin();
+        m.0[1][1] = r.cos();
+        m
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn shearing(xy: f64, xz: f64, yx: f64, yz: f64, zx: f64, zy: f64) -> Self {
+        let mut m = Self::identity();
+        m.0[0][1] = xy;
+        m.0[0][2] = xz;
+        m.0[1][0] = yx;
+        m.0[1][2] = yz;
+        m.0[2][0] = zx;
+        m.0[2][1] = zy;
+        m
+    }
+
+    /// Left-multiplies `self` by a rotation around the x axis, so that it can be
+    /// chained fluently, e.g. `Matrix4::identity().rotate_x(a).scale(2., 2., 2.)`.
+    #[must_use]
+    pub fn rotate_x(self, r: f64) -> Self {
+        Self::rotation_x(r) * self
+    }
+
+    #[must_use]
+    pub fn rotate_y(self, r: f64) -> Self {
+        Self::rotation_y(r) * self
+    }
+
+    #[must_use]
+    pub fn rotate_z(self, r: f64) -> Self {
+        Self::rotation_z(r) * self
+    }
+
+    #[must_use]
+    pub fn scale(self, x: f64, y: f64, z: f64) -> Self {
+        Self::scaling(x, y, z) * self
+    }
+
+    #[must_use]
+    pub fn translate(self, x: f64, y: f64, z: f64) -> Self {
+        Self::translation(x, y, z) * self
+    }
+
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn shear(self, xy: f64, xz: f64, yx: f64, yz: f64, zx: f64, zy: f64) -> Self {
+        Self::shearing(xy, xz, yx, yz, zx, zy) * self
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut result = [[0.; 4]; 4];
+        for (row, cols) in self.0.iter().enumerate() {
+            for (col, &value) in cols.iter().enumerate() {
+                result[col][row] = value;
+            }
+        }
+        Self(result)
+    }
+
+    fn submatrix(&self, row: usize, col: usize) -> [[f64; 3]; 3] {
+        let mut result = [[0.; 3]; 3];
+        for (r, cols) in self.0.iter().enumerate().filter(|&(r, _)| r != row) {
+            let r_out = if r > row { r - 1 } else { r };
+            for (c, &value) in cols.iter().enumerate().filter(|&(c, _)| c != col) {
+                let c_out = if c > col { c - 1 } else { c };
+                result[r_out][c_out] = value;
+            }
+        }
+        result
+    }
+
+    fn minor(&self, row: usize, col: usize) -> f64 {
+        determinant3(&self.submatrix(row, col))
+    }
+
+    fn cofactor(&self, row: usize, col: usize) -> f64 {
+        let minor = self.minor(row, col);
+        if (row + col) % 2 == 1 {
+            -minor
+        } else {
+            minor
+        }
+    }
+
+    fn determinant(&self) -> f64 {
+        (0..4).map(|col| self.0[0][col] * self.cofactor(0, col)).sum()
+    }
+
+    /// Computes the inverse of this matrix, or `None` if it isn't invertible
+    /// (i.e. its determinant is ~0).
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if relative_eq!(det, 0.) {
+            return None;
+        }
+
+        let mut result = [[0.; 4]; 4];
+        for (col, result_col) in result.iter_mut().enumerate() {
+            for (row, value) in result_col.iter_mut().enumerate() {
+                // Note the transposition: cofactor(row, col) lands at [col][row].
+                *value = self.cofactor(row, col) / det;
+            }
+        }
+        Some(Self(result))
+    }
+}
+
+fn submatrix3(m: &[[f64; 3]; 3], row: usize, col: usize) -> [[f64; 2]; 2] {
+    let mut result = [[0.; 2]; 2];
+    for (r, cols) in m.iter().enumerate().filter(|&(r, _)| r != row) {
+        let r_out = if r > row { r - 1 } else { r };
+        for (c, &value) in cols.iter().enumerate().filter(|&(c, _)| c != col) {
+            let c_out = if c > col { c - 1 } else { c };
+            result[r_out][c_out] = value;
+        }
+    }
+    result
+}
+
+fn determinant2(m: &[[f64; 2]; 2]) -> f64 {
+    m[0][0] * m[1][1] - m[0][1] * m[1][0]
+}
+
+fn cofactor3(m: &[[f64; 3]; 3], row: usize, col: usize) -> f64 {
+    let minor = determinant2(&submatrix3(m, row, col));
+    if (row + col) % 2 == 1 {
+        -minor
+    } else {
+        minor
+    }
+}
+
+fn determinant3(m: &[[f64; 3]; 3]) -> f64 {
+    (0..3).map(|col| m[0][col] * cofactor3(m, 0, col)).sum()
+}
+
+impl PartialEq for Matrix4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .all(|(row, other_row)| {
+                row.iter()
+                    .zip(other_row.iter())
+                    .all(|(a, b)| relative_eq!(a, b, epsilon = EPSILON))
+            })
+    }
+}
+
+impl Mul for Matrix4 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut result = [[0.; 4]; 4];
+        for (row, result_row) in result.iter_mut().enumerate() {
+            for (col, value) in result_row.iter_mut().enumerate() {
+                *value = (0..4).map(|i| self.0[row][i] * rhs.0[i][col]).sum();
+            }
+        }
+        Self(result)
+    }
+}
+
+impl Mul<Point> for Matrix4 {
+    type Output = Point;
+
+    fn mul(self, rhs: Point) -> Self::Output {
+        let t = [rhs.x, rhs.y, rhs.z, 1.];
+        Point {
+            x: (0..4).map(|i| self.0[0][i] * t[i]).sum(),
+            y: (0..4).map(|i| self.0[1][i] * t[i]).sum(),
+            z: (0..4).map(|i| self.0[2][i] * t[i]).sum(),
+        }
+    }
+}
+
+impl Mul<Vector> for Matrix4 {
+    type Output = Vector;
+
+    fn mul(self, rhs: Vector) -> Self::Output {
+        let t = [rhs.x, rhs.y, rhs.z, 0.];
+        Vector {
+            x: (0..4).map(|i| self.0[0][i] * t[i]).sum(),
+            y: (0..4).map(|i| self.0[1][i] * t[i]).sum(),
+            z: (0..4).map(|i| self.0[2][i] * t[i]).sum(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multiplying_two_matrices() {
+        let a = Matrix4::identity().translate(1., 2., 3.);
+        let b = Matrix4::identity().scale(2., 2., 2.);
+        assert_eq!(
+            b * a,
+            Matrix4([
+                [2., 0., 0., 2.],
+                [0., 2., 0., 4.],
+                [0., 0., 2., 6.],
+                [0., 0., 0., 1.],
+            ])
+        );
+    }
+
+    #[test]
+    fn a_matrix_multiplied_by_the_identity_matrix_is_unchanged() {
+        let a = Matrix4::identity().translate(1., 2., 3.).scale(4., 5., 6.);
+        assert_eq!(a * Matrix4::identity(), a);
+    }
+
+    #[test]
+    fn transposing_a_matrix() {
+        let a = Matrix4([
+            [0., 9., 3., 0.],
+            [9., 8., 0., 8.],
+            [1., 8., 5., 3.],
+            [0., 0., 5., 8.],
+        ]);
+        assert_eq!(
+            a.transpose(),
+            Matrix4([
+                [0., 9., 1., 0.],
+                [9., 8., 8., 0.],
+                [3., 0., 5., 5.],
+                [0., 8., 3., 8.],
+            ])
+        );
+    }
+
+    #[test]
+    fn transposing_the_identity_matrix() {
+        assert_eq!(Matrix4::identity().transpose(), Matrix4::identity());
+    }
+
+    #[test]
+    fn testing_an_invertible_matrix_for_invertibility() {
+        let a = Matrix4([
+            [6., 4., 4., 4.],
+            [5., 5., 7., 6.],
+            [4., -9., 3., -7.],
+            [9., 1., 7., -6.],
+        ]);
+        assert!(a.inverse().is_some());
+    }
+
+    #[test]
+    fn testing_a_noninvertible_matrix_for_invertibility() {
+        let a = Matrix4([
+            [-4., 2., -2., -3.],
+            [9., 6., 2., 6.],
+            [0., -5., 1., -5.],
+            [0., 0., 0., 0.],
+        ]);
+        assert!(a.inverse().is_none());
+    }
+
+    #[test]
+    fn multiplying_a_product_by_its_inverse() {
+        let a = Matrix4::identity().translate(3., -9., 7.);
+        let b = Matrix4::identity().scale(2., -3., 0.5);
+        let c = a * b;
+        assert_eq!(c * b.inverse().unwrap(), a);
+    }
+
+    #[test]
+    fn multiplying_by_a_translation_matrix() {
+        let transform = Matrix4::translation(5., -3., 2.);
+        let p = Point {
+            x: -3.,
+            y: 4.,
+            z: 5.,
+        };
+        assert_eq!(
+            transform * p,
+            Point {
+                x: 2.,
+                y: 1.,
+                z: 7.,
+            }
+        );
+    }
+
+    #[test]
+    fn multiplying_by_the_inverse_of_a_translation_matrix() {
+        let transform = Matrix4::translation(5., -3., 2.);
+        let inv = transform.inverse().unwrap();
+        let p = Point {
+            x: -3.,
+            y: 4.,
+            z: 5.,
+        };
+        assert_eq!(
+            inv * p,
+            Point {
+                x: -8.,
+                y: 7.,
+                z: 3.,
+            }
+        );
+    }
+
+    #[test]
+    fn translation_does_not_affect_vectors() {
+        let transform = Matrix4::translation(5., -3., 2.);
+        let v = Vector {
+            x: -3.,
+            y: 4.,
+            z: 5.,
+        };
+        assert_eq!(transform * v, v);
+    }
+
+    #[test]
+    fn a_scaling_matrix_applied_to_a_point() {
+        let transform = Matrix4::scaling(2., 3., 4.);
+        let p = Point {
+            x: -4.,
+            y: 6.,
+            z: 8.,
+        };
+        assert_eq!(
+            transform * p,
+            Point {
+                x: -8.,
+                y: 18.,
+                z: 32.,
+            }
+        );
+    }
+
+    #[test]
+    fn a_scaling_matrix_applied_to_a_vector() {
+        let transform = Matrix4::scaling(2., 3., 4.);
+        let v = Vector {
+            x: -4.,
+            y: 6.,
+            z: 8.,
+        };
+        assert_eq!(
+            transform * v,
+            Vector {
+                x: -8.,
+                y: 18.,
+                z: 32.,
+            }
+        );
+    }
+
+    #[test]
+    fn rotating_a_point_around_the_x_axis() {
+        let p = Point {
+            x: 0.,
+            y: 1.,
+            z: 0.,
+        };
+        let half_quarter = Matrix4::rotation_x(std::f64::consts::FRAC_PI_4);
+        let full_quarter = Matrix4::rotation_x(std::f64::consts::FRAC_PI_2);
+        assert_eq!(
+            half_quarter * p,
+            Point {
+                x: 0.,
+                y: 2f64.sqrt() / 2.,
+                z: 2f64.sqrt() / 2.,
+            }
+        );
+        assert_eq!(
+            full_quarter * p,
+            Point {
+                x: 0.,
+                y: 0.,
+                z: 1.,
+            }
+        );
+    }
+
+    #[test]
+    fn rotating_a_point_around_the_z_axis() {
+        let p = Point {
+            x: 0.,
+            y: 1.,
+            z: 0.,
+        };
+        let half_quarter = Matrix4::rotation_z(std::f64::consts::FRAC_PI_4);
+        assert_eq!(
+            half_quarter * p,
+            Point {
+                x: -(2f64.sqrt() / 2.),
+                y: 2f64.sqrt() / 2.,
+                z: 0.,
+            }
+        );
+    }
+
+    #[test]
+    fn a_shearing_transformation_moves_x_in_proportion_to_y() {
+        let transform = Matrix4::shearing(1., 0., 0., 0., 0., 0.);
+        let p = Point {
+            x: 2.,
+            y: 3.,
+            z: 4.,
+        };
+        assert_eq!(
+            transform * p,
+            Point {
+                x: 5.,
+                y: 3.,
+                z: 4.,
+            }
+        );
+    }
+
+    #[test]
+    fn individual_transformations_are_applied_in_sequence() {
+        let p = Point {
+            x: 1.,
+            y: 0.,
+            z: 1.,
+        };
+        let a = Matrix4::rotation_x(std::f64::consts::FRAC_PI_2);
+        let b = Matrix4::scaling(5., 5., 5.);
+        let c = Matrix4::translation(10., 5., 7.);
+
+        let p2 = a * p;
+        assert_eq!(
+            p2,
+            Point {
+                x: 1.,
+                y: -1.,
+                z: 0.,
+            }
+        );
+
+        let p3 = b * p2;
+        assert_eq!(
+            p3,
+            Point {
+                x: 5.,
+                y: -5.,
+                z: 0.,
+            }
+        );
+
+        let p4 = c * p3;
+        assert_eq!(
+            p4,
+            Point {
+                x: 15.,
+                y: 0.,
+                z: 7.,
+            }
+        );
+    }
+
+    #[test]
+    fn chained_transformations_must_be_applied_in_reverse_order() {
+        let p = Point {
+            x: 1.,
+            y: 0.,
+            z: 1.,
+        };
+        let transform = Matrix4::identity()
+            .rotate_x(std::f64::consts::FRAC_PI_2)
+            .scale(5., 5., 5.)
+            .translate(10., 5., 7.);
+        assert_eq!(
+            transform * p,
+            Point {
+                x: 15.,
+                y: 0.,
+                z: 7.,
+            }
+        );
+    }
+}
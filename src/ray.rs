@@ -0,0 +1,351 @@
+use crate::light::Material;
+use crate::matrix::Matrix4;
+use crate::tuple::{Point, Scalar, Vector};
+
+/// A ray cast through the scene, with an `origin` and a `direction`.
+#[derive(Debug, Clone, Copy)]
+pub struct Ray {
+    pub origin: Point,
+    pub direction: Vector,
+}
+
+impl Ray {
+    pub fn new(origin: Point, direction: Vector) -> Self {
+        Self { origin, direction }
+    }
+
+    /// The point reached after travelling `t` units along the ray.
+    pub fn position(&self, t: Scalar) -> Point {
+        self.origin + self.direction * t
+    }
+
+    /// Applies a transformation matrix to both the origin and the direction.
+    pub fn transform(&self, m: &Matrix4) -> Self {
+        Self {
+            origin: *m * self.origin,
+            direction: *m * self.direction,
+        }
+    }
+}
+
+/// A unit sphere, centred on the origin, with its own transformation and material.
+#[derive(Debug, Clone, Copy)]
+pub struct Sphere {
+    pub transform: Matrix4,
+    pub material: Material,
+}
+
+impl Sphere {
+    pub fn new() -> Self {
+        Self {
+            transform: Matrix4::identity(),
+            material: Material::default(),
+        }
+    }
+
+    /// The surface normal at `world_point`, transformed out of object space.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sphere's transform is not invertible.
+    pub fn normal_at(&self, world_point: Point) -> Vector {
+        let inverse = self
+            .transform
+            .inverse()
+            .expect("sphere transform must be invertible");
+
+        let object_point = inverse * world_point;
+        let object_normal = object_point
+            - Point {
+                x: 0.,
+                y: 0.,
+                z: 0.,
+            };
+        let world_normal = inverse.transpose() * object_normal;
+
+        world_normal.normalize()
+    }
+
+    /// Finds where `ray` intersects this sphere, in object space.
+    pub fn intersect(&self, ray: &Ray) -> Vec<Intersection> {
+        let Some(inverse) = self.transform.inverse() else {
+            return vec![];
+        };
+        let ray = ray.transform(&inverse);
+
+        let sphere_to_ray = ray.origin
+            - Point {
+                x: 0.,
+                y: 0.,
+                z: 0.,
+            };
+
+        let a = ray.direction.dot(&ray.direction);
+        let b = Scalar::new(2.) * ray.direction.dot(&sphere_to_ray);
+        let c = sphere_to_ray.dot(&sphere_to_ray) - Scalar::new(1.);
+
+        let discriminant = b * b - Scalar::new(4.) * a * c;
+        if discriminant.is_negative() {
+            return vec![];
+        }
+
+        let sqrt_discriminant = discriminant.sqrt();
+        let t1 = (-b - sqrt_discriminant) / (Scalar::new(2.) * a);
+        let t2 = (-b + sqrt_discriminant) / (Scalar::new(2.) * a);
+
+        vec![
+            Intersection { t: t1, object: *self },
+            Intersection { t: t2, object: *self },
+        ]
+    }
+}
+
+impl Default for Sphere {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The point at which a ray crosses an object, tagged with the object itself.
+#[derive(Debug, Clone, Copy)]
+pub struct Intersection {
+    pub t: Scalar,
+    pub object: Sphere,
+}
+
+/// The visible intersection out of a set: the smallest non-negative `t`.
+/// Intersections whose `t` is `NaN` are skipped.
+pub fn hit(intersections: &[Intersection]) -> Option<&Intersection> {
+    intersections
+        .iter()
+        .filter(|i| i.t >= Scalar::new(0.))
+        .min_by(|a, b| a.t.partial_cmp(&b.t).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(x: f64, y: f64, z: f64) -> Point {
+        Point { x, y, z }
+    }
+
+    fn vector(x: f64, y: f64, z: f64) -> Vector {
+        Vector { x, y, z }
+    }
+
+    #[test]
+    fn creating_and_querying_a_ray() {
+        let origin = point(1., 2., 3.);
+        let direction = vector(4., 5., 6.);
+        let r = Ray::new(origin, direction);
+        assert_eq!(r.origin, origin);
+        assert_eq!(r.direction, direction);
+    }
+
+    #[test]
+    fn computing_a_point_from_a_distance() {
+        let r = Ray::new(point(2., 3., 4.), vector(1., 0., 0.));
+        assert_eq!(r.position(Scalar::new(0.)), point(2., 3., 4.));
+        assert_eq!(r.position(Scalar::new(1.)), point(3., 3., 4.));
+        assert_eq!(r.position(Scalar::new(-1.)), point(1., 3., 4.));
+        assert_eq!(r.position(Scalar::new(2.5)), point(4.5, 3., 4.));
+    }
+
+    #[test]
+    fn a_ray_intersects_a_sphere_at_two_points() {
+        let r = Ray::new(point(0., 0., -5.), vector(0., 0., 1.));
+        let s = Sphere::new();
+        let xs = s.intersect(&r);
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, Scalar::new(4.));
+        assert_eq!(xs[1].t, Scalar::new(6.));
+    }
+
+    #[test]
+    fn a_ray_intersects_a_sphere_at_a_tangent() {
+        let r = Ray::new(point(0., 1., -5.), vector(0., 0., 1.));
+        let s = Sphere::new();
+        let xs = s.intersect(&r);
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, Scalar::new(5.));
+        assert_eq!(xs[1].t, Scalar::new(5.));
+    }
+
+    #[test]
+    fn a_ray_misses_a_sphere() {
+        let r = Ray::new(point(0., 2., -5.), vector(0., 0., 1.));
+        let s = Sphere::new();
+        assert!(s.intersect(&r).is_empty());
+    }
+
+    #[test]
+    fn a_ray_originates_inside_a_sphere() {
+        let r = Ray::new(point(0., 0., 0.), vector(0., 0., 1.));
+        let s = Sphere::new();
+        let xs = s.intersect(&r);
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, Scalar::new(-1.));
+        assert_eq!(xs[1].t, Scalar::new(1.));
+    }
+
+    #[test]
+    fn a_sphere_is_behind_a_ray() {
+        let r = Ray::new(point(0., 0., 5.), vector(0., 0., 1.));
+        let s = Sphere::new();
+        let xs = s.intersect(&r);
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, Scalar::new(-6.));
+        assert_eq!(xs[1].t, Scalar::new(-4.));
+    }
+
+    #[test]
+    fn translating_a_ray() {
+        let r = Ray::new(point(1., 2., 3.), vector(0., 1., 0.));
+        let m = Matrix4::translation(3., 4., 5.);
+        let r2 = r.transform(&m);
+        assert_eq!(r2.origin, point(4., 6., 8.));
+        assert_eq!(r2.direction, vector(0., 1., 0.));
+    }
+
+    #[test]
+    fn scaling_a_ray() {
+        let r = Ray::new(point(1., 2., 3.), vector(0., 1., 0.));
+        let m = Matrix4::scaling(2., 3., 4.);
+        let r2 = r.transform(&m);
+        assert_eq!(r2.origin, point(2., 6., 12.));
+        assert_eq!(r2.direction, vector(0., 3., 0.));
+    }
+
+    #[test]
+    fn a_spheres_default_transformation() {
+        let s = Sphere::new();
+        assert_eq!(s.transform, Matrix4::identity());
+    }
+
+    #[test]
+    fn intersecting_a_scaled_sphere_with_a_ray() {
+        let r = Ray::new(point(0., 0., -5.), vector(0., 0., 1.));
+        let mut s = Sphere::new();
+        s.transform = Matrix4::scaling(2., 2., 2.);
+        let xs = s.intersect(&r);
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, Scalar::new(3.));
+        assert_eq!(xs[1].t, Scalar::new(7.));
+    }
+
+    #[test]
+    fn intersecting_a_translated_sphere_with_a_ray() {
+        let r = Ray::new(point(0., 0., -5.), vector(0., 0., 1.));
+        let mut s = Sphere::new();
+        s.transform = Matrix4::translation(5., 0., 0.);
+        assert!(s.intersect(&r).is_empty());
+    }
+
+    #[test]
+    fn the_normal_on_a_sphere_at_a_point_on_the_x_axis() {
+        let s = Sphere::new();
+        assert_eq!(s.normal_at(point(1., 0., 0.)), vector(1., 0., 0.));
+    }
+
+    #[test]
+    fn the_normal_on_a_sphere_at_a_nonaxial_point() {
+        let s = Sphere::new();
+        let n = s.normal_at(point(
+            3f64.sqrt() / 3.,
+            3f64.sqrt() / 3.,
+            3f64.sqrt() / 3.,
+        ));
+        assert_eq!(
+            n,
+            vector(3f64.sqrt() / 3., 3f64.sqrt() / 3., 3f64.sqrt() / 3.)
+        );
+        assert_eq!(n, n.normalize());
+    }
+
+    #[test]
+    fn the_normal_on_a_translated_sphere() {
+        let mut s = Sphere::new();
+        s.transform = Matrix4::translation(0., 1., 0.);
+        let n = s.normal_at(point(0., 1. + 2f64.sqrt() / 2., -(2f64.sqrt() / 2.)));
+        assert_eq!(n, vector(0., 2f64.sqrt() / 2., -(2f64.sqrt() / 2.)));
+    }
+
+    #[test]
+    fn the_normal_on_a_transformed_sphere() {
+        let mut s = Sphere::new();
+        s.transform = Matrix4::identity()
+            .rotate_z(std::f64::consts::PI / 5.)
+            .scale(1., 0.5, 1.);
+        let n = s.normal_at(point(0., 2f64.sqrt() / 2., -(2f64.sqrt() / 2.)));
+        assert_eq!(n, vector(0., 0.97014, -0.24254));
+    }
+
+    #[test]
+    fn the_hit_when_all_intersections_have_positive_t() {
+        let s = Sphere::new();
+        let i1 = Intersection {
+            t: Scalar::new(1.),
+            object: s,
+        };
+        let i2 = Intersection {
+            t: Scalar::new(2.),
+            object: s,
+        };
+        let xs = [i2, i1];
+        assert_eq!(hit(&xs).unwrap().t, Scalar::new(1.));
+    }
+
+    #[test]
+    fn the_hit_when_some_intersections_have_negative_t() {
+        let s = Sphere::new();
+        let i1 = Intersection {
+            t: Scalar::new(-1.),
+            object: s,
+        };
+        let i2 = Intersection {
+            t: Scalar::new(1.),
+            object: s,
+        };
+        let xs = [i2, i1];
+        assert_eq!(hit(&xs).unwrap().t, Scalar::new(1.));
+    }
+
+    #[test]
+    fn the_hit_when_all_intersections_have_negative_t() {
+        let s = Sphere::new();
+        let i1 = Intersection {
+            t: Scalar::new(-2.),
+            object: s,
+        };
+        let i2 = Intersection {
+            t: Scalar::new(-1.),
+            object: s,
+        };
+        let xs = [i2, i1];
+        assert!(hit(&xs).is_none());
+    }
+
+    #[test]
+    fn the_hit_is_always_the_lowest_nonnegative_intersection() {
+        let s = Sphere::new();
+        let i1 = Intersection {
+            t: Scalar::new(5.),
+            object: s,
+        };
+        let i2 = Intersection {
+            t: Scalar::new(7.),
+            object: s,
+        };
+        let i3 = Intersection {
+            t: Scalar::new(-3.),
+            object: s,
+        };
+        let i4 = Intersection {
+            t: Scalar::new(2.),
+            object: s,
+        };
+        let xs = [i1, i2, i3, i4];
+        assert_eq!(hit(&xs).unwrap().t, Scalar::new(2.));
+    }
+}
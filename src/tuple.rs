@@ -2,14 +2,18 @@ use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
 
 use approx::relative_eq;
 
-#[derive(Debug)]
+/// Tolerance used when comparing floating-point components, loose enough to absorb
+/// the rounding error that accumulates across chained matrix transformations.
+pub(crate) const EPSILON: f64 = 1e-5;
+
+#[derive(Debug, Clone, Copy)]
 pub struct Point {
     pub x: f64,
     pub y: f64,
     pub z: f64,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct Vector {
     pub x: f64,
     pub y: f64,
@@ -23,6 +27,14 @@ impl Scalar {
     pub fn new(value: f64) -> Self {
         Self(value)
     }
+
+    pub fn sqrt(self) -> Self {
+        Self(self.0.sqrt())
+    }
+
+    pub fn is_negative(self) -> bool {
+        self.0 < 0.
+    }
 }
 
 impl PartialOrd for Scalar {
@@ -33,23 +45,69 @@ impl PartialOrd for Scalar {
 
 impl PartialEq for Point {
     fn eq(&self, other: &Self) -> bool {
-        relative_eq!(self.x, other.x)
-            && relative_eq!(self.y, other.y)
-            && relative_eq!(self.z, other.z)
+        relative_eq!(self.x, other.x, epsilon = EPSILON)
+            && relative_eq!(self.y, other.y, epsilon = EPSILON)
+            && relative_eq!(self.z, other.z, epsilon = EPSILON)
     }
 }
 
 impl PartialEq for Vector {
     fn eq(&self, other: &Self) -> bool {
-        relative_eq!(self.x, other.x)
-            && relative_eq!(self.y, other.y)
-            && relative_eq!(self.z, other.z)
+        relative_eq!(self.x, other.x, epsilon = EPSILON)
+            && relative_eq!(self.y, other.y, epsilon = EPSILON)
+            && relative_eq!(self.z, other.z, epsilon = EPSILON)
     }
 }
 
 impl PartialEq for Scalar {
     fn eq(&self, other: &Self) -> bool {
-        relative_eq!(self.0, other.0)
+        relative_eq!(self.0, other.0, epsilon = EPSILON)
+    }
+}
+
+impl Add for Scalar {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Scalar {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Mul for Scalar {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(self.0 * rhs.0)
+    }
+}
+
+impl Div for Scalar {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self(self.0 / rhs.0)
+    }
+}
+
+impl Neg for Scalar {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl From<Scalar> for f64 {
+    fn from(scalar: Scalar) -> Self {
+        scalar.0
     }
 }
 
@@ -202,7 +260,73 @@ impl Div<Scalar> for Vector {
     }
 }
 
+impl Point {
+    pub const ORIGIN: Self = Self {
+        x: 0.,
+        y: 0.,
+        z: 0.,
+    };
+
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn distance(&self, other: &Self) -> Scalar {
+        (*self - *other).magnitude()
+    }
+}
+
 impl Vector {
+    pub const ZERO: Self = Self {
+        x: 0.,
+        y: 0.,
+        z: 0.,
+    };
+    pub const ONE: Self = Self {
+        x: 1.,
+        y: 1.,
+        z: 1.,
+    };
+    pub const X: Self = Self {
+        x: 1.,
+        y: 0.,
+        z: 0.,
+    };
+    pub const Y: Self = Self {
+        x: 0.,
+        y: 1.,
+        z: 0.,
+    };
+    pub const Z: Self = Self {
+        x: 0.,
+        y: 0.,
+        z: 1.,
+    };
+    pub const NEG_X: Self = Self {
+        x: -1.,
+        y: 0.,
+        z: 0.,
+    };
+    pub const NEG_Y: Self = Self {
+        x: 0.,
+        y: -1.,
+        z: 0.,
+    };
+    pub const NEG_Z: Self = Self {
+        x: 0.,
+        y: 0.,
+        z: -1.,
+    };
+
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Whether this vector is (within tolerance) the zero vector.
+    pub fn is_zero(&self) -> bool {
+        self.magnitude() == Scalar::new(0.)
+    }
+
     pub fn magnitude(&self) -> Scalar {
         Scalar((self.x * self.x + self.y * self.y + self.z * self.z).sqrt())
     }
@@ -227,6 +351,11 @@ impl Vector {
             z: self.x * rhs.y - self.y * rhs.x,
         }
     }
+
+    /// Reflects this vector about `normal`.
+    pub fn reflect(&self, normal: &Self) -> Self {
+        *self - *normal * (self.dot(normal) * Scalar::new(2.))
+    }
 }
 
 #[cfg(test)]
@@ -533,4 +662,80 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn reflecting_a_vector_approaching_at_45deg() {
+        let v = Vector {
+            x: 1.,
+            y: -1.,
+            z: 0.,
+        };
+        let n = Vector {
+            x: 0.,
+            y: 1.,
+            z: 0.,
+        };
+        assert_eq!(
+            v.reflect(&n),
+            Vector {
+                x: 1.,
+                y: 1.,
+                z: 0.,
+            }
+        );
+    }
+
+    #[test]
+    fn reflecting_a_vector_off_a_slanted_surface() {
+        let v = Vector {
+            x: 0.,
+            y: -1.,
+            z: 0.,
+        };
+        let n = Vector {
+            x: 2f64.sqrt() / 2.,
+            y: 2f64.sqrt() / 2.,
+            z: 0.,
+        };
+        assert_eq!(
+            v.reflect(&n),
+            Vector {
+                x: 1.,
+                y: 0.,
+                z: 0.,
+            }
+        );
+    }
+
+    #[test]
+    fn vector_and_point_constructors() {
+        assert_eq!(Vector::new(1., 2., 3.), Vector { x: 1., y: 2., z: 3. });
+        assert_eq!(Point::new(1., 2., 3.), Point { x: 1., y: 2., z: 3. });
+    }
+
+    #[test]
+    fn named_vector_constants() {
+        assert_eq!(Vector::ZERO, Vector::new(0., 0., 0.));
+        assert_eq!(Vector::ONE, Vector::new(1., 1., 1.));
+        assert_eq!(Vector::X, Vector::new(1., 0., 0.));
+        assert_eq!(Vector::Y, Vector::new(0., 1., 0.));
+        assert_eq!(Vector::Z, Vector::new(0., 0., 1.));
+        assert_eq!(Vector::NEG_X, -Vector::X);
+        assert_eq!(Vector::NEG_Y, -Vector::Y);
+        assert_eq!(Vector::NEG_Z, -Vector::Z);
+        assert_eq!(Point::ORIGIN, Point::new(0., 0., 0.));
+    }
+
+    #[test]
+    fn checking_whether_a_vector_is_zero() {
+        assert!(Vector::ZERO.is_zero());
+        assert!(!Vector::X.is_zero());
+    }
+
+    #[test]
+    fn distance_between_two_points() {
+        let a = Point::new(0., 0., 0.);
+        let b = Point::new(3., 4., 0.);
+        assert_eq!(a.distance(&b), Scalar::new(5.));
+    }
 }
@@ -0,0 +1,106 @@
+use crate::color::Color;
+use crate::light::{lighting, PointLight};
+use crate::ray::{hit, Intersection, Ray, Sphere};
+
+/// A collection of objects and a single light, ready to be rendered.
+pub struct World {
+    pub objects: Vec<Sphere>,
+    pub light: Option<PointLight>,
+}
+
+impl World {
+    pub fn new() -> Self {
+        Self {
+            objects: Vec::new(),
+            light: None,
+        }
+    }
+
+    fn intersect(&self, ray: &Ray) -> Vec<Intersection> {
+        let mut xs: Vec<Intersection> = self.objects.iter().flat_map(|o| o.intersect(ray)).collect();
+        xs.sort_by(|a, b| a.t.partial_cmp(&b.t).unwrap());
+        xs
+    }
+
+    /// The color seen along `ray`: black if it hits nothing or there is no light.
+    pub fn color_at(&self, ray: &Ray) -> Color {
+        let black = Color::new(0., 0., 0.);
+
+        let xs = self.intersect(ray);
+        let (Some(i), Some(light)) = (hit(&xs), self.light) else {
+            return black;
+        };
+
+        let point = ray.position(i.t);
+        let eyev = -ray.direction;
+        let normalv = i.object.normal_at(point);
+
+        lighting(&i.object.material, &light, point, eyev, normalv)
+    }
+}
+
+impl Default for World {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::light::Material;
+    use crate::matrix::Matrix4;
+    use crate::tuple::{Point, Vector};
+
+    fn point(x: f64, y: f64, z: f64) -> Point {
+        Point { x, y, z }
+    }
+
+    fn vector(x: f64, y: f64, z: f64) -> Vector {
+        Vector { x, y, z }
+    }
+
+    fn default_world() -> World {
+        let light = PointLight::new(point(-10., 10., -10.), Color::new(1., 1., 1.));
+
+        let mut s1 = Sphere::new();
+        s1.material = Material {
+            color: Color::new(0.8, 1.0, 0.6),
+            diffuse: 0.7,
+            specular: 0.2,
+            ..Material::default()
+        };
+
+        let mut s2 = Sphere::new();
+        s2.transform = Matrix4::scaling(0.5, 0.5, 0.5);
+
+        World {
+            objects: vec![s1, s2],
+            light: Some(light),
+        }
+    }
+
+    #[test]
+    fn the_color_when_a_ray_misses() {
+        let w = default_world();
+        let r = Ray::new(point(0., 0., -5.), vector(0., 1., 0.));
+        assert_eq!(w.color_at(&r), Color::new(0., 0., 0.));
+    }
+
+    #[test]
+    fn the_color_when_a_ray_hits() {
+        let w = default_world();
+        let r = Ray::new(point(0., 0., -5.), vector(0., 0., 1.));
+        assert_eq!(w.color_at(&r), Color::new(0.38066, 0.47583, 0.2855));
+    }
+
+    #[test]
+    fn the_color_with_an_intersection_behind_the_ray() {
+        let mut w = default_world();
+        w.objects[0].material.ambient = 1.;
+        w.objects[1].material.ambient = 1.;
+        let inner_color = w.objects[1].material.color;
+        let r = Ray::new(point(0., 0., 0.75), vector(0., 0., -1.));
+        assert_eq!(w.color_at(&r), inner_color);
+    }
+}